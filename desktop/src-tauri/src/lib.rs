@@ -19,7 +19,12 @@ pub fn run() {
             terminal::terminal_create,
             terminal::terminal_write,
             terminal::terminal_resize,
-            terminal::terminal_kill
+            terminal::terminal_kill,
+            terminal::terminal_get_screen,
+            terminal::terminal_scroll,
+            terminal::terminal_get_title,
+            terminal::terminal_list,
+            terminal::terminal_status
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
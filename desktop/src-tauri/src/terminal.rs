@@ -1,11 +1,21 @@
 use std::{
   collections::HashMap,
   io::{Read, Write},
-  sync::Mutex,
+  net::TcpStream,
+  path::Path,
+  sync::{Arc, Mutex},
 };
 
+use alacritty_terminal::{
+  event::{Event as AlacrittyEvent, EventListener},
+  grid::{Dimensions, Scroll},
+  term::{Config as TermConfig, Term},
+  vte::ansi::Processor,
+};
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine as _};
 use portable_pty::{native_pty_system, CommandBuilder, MasterPty, PtySize};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+use ssh2::Session as SshSession;
 use tauri::{AppHandle, Emitter, State};
 use uuid::Uuid;
 
@@ -23,10 +33,396 @@ impl TerminalState {
   }
 }
 
+/// Size handed to `alacritty_terminal::Term`; mirrors the PTY's `cols`/`rows`.
+#[derive(Clone, Copy)]
+struct TermSize {
+  cols: usize,
+  rows: usize,
+}
+
+impl Dimensions for TermSize {
+  fn total_lines(&self) -> usize {
+    self.rows
+  }
+
+  fn screen_lines(&self) -> usize {
+    self.rows
+  }
+
+  fn columns(&self) -> usize {
+    self.cols
+  }
+}
+
+/// Forwards `alacritty_terminal`'s upward events to Tauri events, mirroring
+/// the `TitleChanged`/`Bell` events the Zed terminal model raises to its UI.
+#[derive(Clone)]
+struct EventProxy {
+  app: AppHandle,
+  id: String,
+  title: Arc<Mutex<String>>,
+}
+
+impl EventListener for EventProxy {
+  fn send_event(&self, event: AlacrittyEvent) {
+    match event {
+      AlacrittyEvent::Title(title) => {
+        *self.title.lock().expect("terminal title poisoned") = title.clone();
+        let _ = self.app.emit(
+          "terminal:title",
+          TerminalTitleEvent {
+            id: self.id.clone(),
+            title,
+          },
+        );
+      }
+      AlacrittyEvent::ResetTitle => {
+        let mut current = self.title.lock().expect("terminal title poisoned");
+        current.clear();
+        let _ = self.app.emit(
+          "terminal:title",
+          TerminalTitleEvent {
+            id: self.id.clone(),
+            title: current.clone(),
+          },
+        );
+      }
+      AlacrittyEvent::Bell => {
+        let _ = self.app.emit(
+          "terminal:bell",
+          TerminalBellEvent {
+            id: self.id.clone(),
+          },
+        );
+      }
+      _ => {}
+    }
+  }
+}
+
+/// A session's underlying shell: a local `portable_pty` child, or a shell
+/// opened over an SSH channel on a remote host. `terminal_resize`/`kill`
+/// dispatch on this (writes go through the session's `writer`) without the
+/// frontend protocol changing.
+enum PtyBackend {
+  Local {
+    master: Box<dyn MasterPty + Send>,
+    // The direct child of `spawn_command`. When `sandbox.new_pid_ns` is set
+    // this is a tiny reaper process, NOT the shell itself - see `real_pid`.
+    child: Box<dyn portable_pty::Child + Send>,
+    // The sandboxed shell's actual PID, when it differs from `child`'s
+    // (i.e. a new PID namespace forced an extra fork). `kill`/liveness
+    // checks must target this PID directly instead of `child`.
+    real_pid: Option<i32>,
+  },
+  Remote {
+    channel: Arc<Mutex<ssh2::Channel>>,
+    // Keeps the SSH session (and its TCP connection) alive for the channel's lifetime.
+    _session: SshSession,
+  },
+}
+
 struct TermSession {
-  master: Box<dyn MasterPty + Send>,
+  backend: PtyBackend,
   writer: Box<dyn Write + Send>,
-  child: Box<dyn portable_pty::Child + Send>,
+  term: Arc<Mutex<Term<EventProxy>>>,
+  title: Arc<Mutex<String>>,
+  cols: Mutex<u16>,
+  rows: Mutex<u16>,
+  /// Set once the child/channel is observed to have exited; `None` while running.
+  exit_status: Mutex<Option<u32>>,
+}
+
+/// Checks whether a session's backend is still running, recording its exit
+/// code the moment it's observed (via `child.try_wait()` for local PTYs, or
+/// channel EOF for remote ones).
+fn backend_alive(backend: &mut PtyBackend, exit_status: &Mutex<Option<u32>>) -> bool {
+  match backend {
+    PtyBackend::Local {
+      child, real_pid, ..
+    } => {
+      // Sandboxed sessions: `child` is a reaper, not the shell - signal 0
+      // against the real PID to check liveness directly.
+      #[cfg(unix)]
+      if let Some(pid) = real_pid {
+        let alive = unsafe { libc::kill(*pid, 0) == 0 };
+        if !alive {
+          if let Ok(Some(status)) = child.try_wait() {
+            *exit_status.lock().expect("terminal exit status poisoned") = Some(status.exit_code());
+          }
+        }
+        return alive;
+      }
+      #[cfg(not(unix))]
+      let _ = real_pid;
+
+      match child.try_wait() {
+        Ok(None) => true,
+        Ok(Some(status)) => {
+          *exit_status.lock().expect("terminal exit status poisoned") = Some(status.exit_code());
+          false
+        }
+        Err(_) => false,
+      }
+    }
+    PtyBackend::Remote { channel, .. } => {
+      let channel = channel.lock().expect("ssh channel poisoned");
+      let eof = channel.eof();
+      if eof {
+        let code = channel.exit_status().map(|code| code as u32).unwrap_or(0);
+        *exit_status.lock().expect("terminal exit status poisoned") = Some(code);
+      }
+      !eof
+    }
+  }
+}
+
+/// Host to open a remote shell on over SSH, as an alternative to a local
+/// `portable_pty` child.
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RemoteTarget {
+  host: String,
+  user: String,
+  port: Option<u16>,
+  identity: Option<String>,
+}
+
+/// Adapts a shared SSH channel to `Read`/`Write` so it can be plugged into
+/// the same reader-thread/writer-field plumbing as a local PTY.
+///
+/// The session is put in non-blocking mode (see `open_remote_shell`), so a
+/// read with nothing to return is a `WouldBlock` error rather than a block
+/// that would otherwise sit on the mutex forever - starving `terminal_write`
+/// until the remote happened to send more output on its own.
+struct ChannelIo(Arc<Mutex<ssh2::Channel>>);
+
+const WOULD_BLOCK_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(10);
+
+/// Retries `op` against the channel until it stops returning `WouldBlock`,
+/// dropping the lock between attempts so other threads can acquire it.
+fn retry_on_would_block<T>(
+  channel: &Mutex<ssh2::Channel>,
+  mut op: impl FnMut(&mut ssh2::Channel) -> std::io::Result<T>,
+) -> std::io::Result<T> {
+  loop {
+    let result = op(&mut channel.lock().expect("ssh channel poisoned"));
+    match result {
+      Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => {
+        std::thread::sleep(WOULD_BLOCK_POLL_INTERVAL);
+      }
+      other => return other,
+    }
+  }
+}
+
+impl Read for ChannelIo {
+  fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+    retry_on_would_block(&self.0, |channel| channel.read(buf))
+  }
+}
+
+impl Write for ChannelIo {
+  fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+    retry_on_would_block(&self.0, |channel| channel.write(buf))
+  }
+
+  fn flush(&mut self) -> std::io::Result<()> {
+    retry_on_would_block(&self.0, |channel| channel.flush())
+  }
+}
+
+/// Opt-in namespace isolation for a locally-spawned shell, configured before
+/// `spawn_command` via a pre-exec hook. Mirrors the knobs a container
+/// runtime like youki sets up: fresh PID/mount/network namespaces and a uid
+/// mapping for the new user namespace. `allowed_paths` is rejected for now -
+/// see `apply_sandbox`.
+///
+/// Every field here is only ever read by the Linux-only `apply_sandbox`; the
+/// struct itself has to stay cross-platform since it's part of
+/// `terminal_create`'s parameter type on every target, so the fields would
+/// otherwise trip `dead_code` on non-Linux builds.
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+pub struct SandboxOptions {
+  new_pid_ns: Option<bool>,
+  new_net_ns: Option<bool>,
+  new_mount_ns: Option<bool>,
+  uid_map: Option<String>,
+  allowed_paths: Option<Vec<String>>,
+}
+
+/// Unshares the requested namespaces and, for a new user namespace, maps
+/// the calling user to root inside it so the other namespaces can be
+/// created without host privileges. Runs inside the child, right before
+/// exec.
+///
+/// `pid_report_fd`, when a new PID namespace is requested, is the write end
+/// of a pipe back to `terminal_create`: since the extra fork below means
+/// the process the caller is about to exec is no longer `child`'s pid, the
+/// real pid is reported through this fd instead.
+#[cfg(target_os = "linux")]
+fn apply_sandbox(
+  options: &SandboxOptions,
+  pid_report_fd: Option<std::os::fd::RawFd>,
+) -> std::io::Result<()> {
+  // Checked unconditionally - this must not depend on `new_mount_ns` being
+  // set, or a caller that passes `allowed_paths` alone gets no error and no
+  // restriction at all.
+  if !options
+    .allowed_paths
+    .as_deref()
+    .unwrap_or(&[])
+    .is_empty()
+  {
+    return Err(std::io::Error::new(
+      std::io::ErrorKind::Unsupported,
+      "sandbox.allowed_paths is not enforced yet (no filesystem jail); omit it",
+    ));
+  }
+
+  let want_pid_ns = options.new_pid_ns.unwrap_or(false);
+  let want_mount_ns = options.new_mount_ns.unwrap_or(false);
+
+  let mut flags = 0;
+  if want_pid_ns {
+    flags |= libc::CLONE_NEWPID;
+  }
+  if options.new_net_ns.unwrap_or(false) {
+    flags |= libc::CLONE_NEWNET;
+  }
+  if want_mount_ns {
+    // A new mount namespace needs a new user namespace too, so the child
+    // can remount/bind-mount without host root.
+    flags |= libc::CLONE_NEWNS | libc::CLONE_NEWUSER;
+  }
+
+  if flags != 0 && unsafe { libc::unshare(flags) } != 0 {
+    return Err(std::io::Error::last_os_error());
+  }
+
+  if flags & libc::CLONE_NEWUSER != 0 {
+    let uid = unsafe { libc::getuid() };
+    let map = options
+      .uid_map
+      .clone()
+      .unwrap_or_else(|| format!("0 {uid} 1"));
+    std::fs::write("/proc/self/setgroups", "deny")?;
+    std::fs::write("/proc/self/uid_map", &map)?;
+    std::fs::write("/proc/self/gid_map", &map)?;
+  }
+
+  if want_pid_ns {
+    // Per unshare(2): CLONE_NEWPID only affects processes forked *after*
+    // this call, not the caller itself - without an extra fork the shell
+    // we're about to exec would silently stay in the host PID namespace.
+    // Fork once more so the grandchild (which falls through to exec) is
+    // the one that lands in the new namespace as its PID 1; this process
+    // becomes a tiny init that reaps it and relays its exit status.
+    match unsafe { libc::fork() } {
+      -1 => return Err(std::io::Error::last_os_error()),
+      0 => {
+        // Grandchild: this is the process that will exec the shell. It
+        // doesn't report a pid of its own; only the reaper below writes.
+        if let Some(fd) = pid_report_fd {
+          unsafe { libc::close(fd) };
+        }
+      }
+      pid => {
+        if let Some(fd) = pid_report_fd {
+          let bytes = pid.to_ne_bytes();
+          unsafe { libc::write(fd, bytes.as_ptr().cast(), bytes.len()) };
+          unsafe { libc::close(fd) };
+        }
+
+        let mut status: libc::c_int = 0;
+        unsafe { libc::waitpid(pid, &mut status, 0) };
+        let code = unsafe {
+          if libc::WIFEXITED(status) {
+            libc::WEXITSTATUS(status)
+          } else {
+            128 + libc::WTERMSIG(status)
+          }
+        };
+        unsafe { libc::_exit(code) };
+      }
+    }
+  }
+
+  if want_mount_ns {
+    restrict_filesystem()?;
+  }
+
+  Ok(())
+}
+
+/// Detaches the new mount namespace from the host's so nothing the child
+/// mounts (or unmounts) propagates back. `allowed_paths` enforcement (a
+/// real filesystem jail needs a minimal rootfs to pivot_root into) is
+/// rejected up front in `apply_sandbox`, not here.
+#[cfg(target_os = "linux")]
+fn restrict_filesystem() -> std::io::Result<()> {
+  let root = std::ffi::CString::new("/").expect("no interior nul");
+  let rc = unsafe {
+    libc::mount(
+      std::ptr::null(),
+      root.as_ptr(),
+      std::ptr::null(),
+      libc::MS_PRIVATE | libc::MS_REC,
+      std::ptr::null(),
+    )
+  };
+  if rc != 0 {
+    return Err(std::io::Error::last_os_error());
+  }
+
+  Ok(())
+}
+
+/// Connects, authenticates (via the given identity file or the running
+/// SSH agent), and opens an interactive PTY shell on the remote host.
+fn open_remote_shell(
+  remote: &RemoteTarget,
+  cols: u16,
+  rows: u16,
+) -> Result<(SshSession, ssh2::Channel), String> {
+  let port = remote.port.unwrap_or(22);
+  let tcp = TcpStream::connect((remote.host.as_str(), port)).map_err(|e| e.to_string())?;
+
+  let mut session = SshSession::new().map_err(|e| e.to_string())?;
+  session.set_tcp_stream(tcp);
+  session.handshake().map_err(|e| e.to_string())?;
+
+  match &remote.identity {
+    Some(identity) => session
+      .userauth_pubkey_file(&remote.user, None, Path::new(identity), None)
+      .map_err(|e| e.to_string())?,
+    None => session
+      .userauth_agent(&remote.user)
+      .map_err(|e| e.to_string())?,
+  }
+
+  if !session.authenticated() {
+    return Err("ssh authentication failed".to_string());
+  }
+
+  let mut channel = session.channel_session().map_err(|e| e.to_string())?;
+  channel
+    .request_pty(
+      "xterm-256color",
+      None,
+      Some((cols as u32, rows as u32, 0, 0)),
+    )
+    .map_err(|e| e.to_string())?;
+  channel.shell().map_err(|e| e.to_string())?;
+
+  // Switch to non-blocking for the interactive phase so a quiet remote
+  // (e.g. sitting at a prompt) can't park the reader thread inside a
+  // blocking read while it holds the channel's lock - see `ChannelIo`.
+  session.set_blocking(false);
+
+  Ok((session, channel))
 }
 
 #[derive(Clone, Serialize)]
@@ -42,6 +438,83 @@ struct TerminalExitEvent {
   id: String,
 }
 
+/// Emitted instead of `terminal:data` when the session was created with
+/// `binary_safe: true`; `data` is the raw chunk, base64-encoded.
+#[derive(Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TerminalBinaryDataEvent {
+  id: String,
+  data: String,
+}
+
+/// Emitted when the shell sets the window title via OSC 0/2.
+#[derive(Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TerminalTitleEvent {
+  id: String,
+  title: String,
+}
+
+/// Emitted when the shell rings the bell (`0x07`).
+#[derive(Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TerminalBellEvent {
+  id: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TerminalCell {
+  row: usize,
+  col: usize,
+  ch: char,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TerminalCursor {
+  row: usize,
+  col: usize,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TerminalScreen {
+  cols: usize,
+  rows: usize,
+  cells: Vec<TerminalCell>,
+  cursor: TerminalCursor,
+}
+
+/// One row of `terminal_list`'s summary of every known session.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TerminalSummary {
+  id: String,
+  title: String,
+  cols: u16,
+  rows: u16,
+  alive: bool,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TerminalStatus {
+  alive: bool,
+  exit_status: Option<u32>,
+}
+
+/// Which program `terminal_create` should launch. Mirrors the `Shell` enum
+/// editor terminals use: the default interactive shell, a bare program, or a
+/// program with explicit arguments.
+#[derive(Clone, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum Shell {
+  System,
+  Program { program: String },
+  WithArguments { program: String, args: Vec<String> },
+}
+
 fn default_shell_command() -> CommandBuilder {
   #[cfg(target_os = "windows")]
   {
@@ -57,6 +530,74 @@ fn default_shell_command() -> CommandBuilder {
   }
 }
 
+/// Builds the `CommandBuilder` for a session from the caller's `shell`
+/// choice, falling back to the current default interactive shell, then
+/// layers in `env` on top of alacritty-style color defaults.
+fn build_command(shell: Option<Shell>, env: Option<HashMap<String, String>>) -> CommandBuilder {
+  let mut cmd = match shell {
+    Some(Shell::System) | None => default_shell_command(),
+    Some(Shell::Program { program }) => CommandBuilder::new(program),
+    Some(Shell::WithArguments { program, args }) => {
+      let mut cmd = CommandBuilder::new(program);
+      cmd.args(args);
+      cmd
+    }
+  };
+
+  // Sensible defaults for color-aware programs, as alacritty's `setup_env` does.
+  cmd.env("TERM", "xterm-256color");
+  cmd.env("COLORTERM", "truecolor");
+
+  if let Some(env) = env {
+    for (key, value) in env {
+      cmd.env(key, value);
+    }
+  }
+
+  cmd
+}
+
+/// Feeds `chunk` onto the trailing bytes left over from the previous read
+/// and returns the text that's now safe to emit, leaving anything still
+/// incomplete in `carry` for the next call.
+///
+/// A truncated multibyte sequence at the end of `carry` (`error_len() ==
+/// None`) is kept for next time. A genuinely invalid byte sequence
+/// (`error_len() == Some(_)`) is replaced with U+FFFD and skipped, so one
+/// bad byte can't stall output forever.
+fn decode_chunk(carry: &mut Vec<u8>, chunk: &[u8]) -> String {
+  carry.extend_from_slice(chunk);
+
+  let mut text = String::new();
+  loop {
+    match std::str::from_utf8(carry) {
+      Ok(s) => {
+        text.push_str(s);
+        carry.clear();
+        return text;
+      }
+      Err(e) => {
+        let valid_up_to = e.valid_up_to();
+        text.push_str(
+          std::str::from_utf8(&carry[..valid_up_to])
+            .expect("valid_up_to guarantees a valid prefix"),
+        );
+
+        match e.error_len() {
+          Some(bad_len) => {
+            text.push('\u{FFFD}');
+            carry.drain(..valid_up_to + bad_len);
+          }
+          None => {
+            carry.drain(..valid_up_to);
+            return text;
+          }
+        }
+      }
+    }
+  }
+}
+
 #[tauri::command]
 pub fn terminal_create(
   app: AppHandle,
@@ -64,29 +605,141 @@ pub fn terminal_create(
   cols: u16,
   rows: u16,
   cwd: Option<String>,
+  shell: Option<Shell>,
+  env: Option<HashMap<String, String>>,
+  binary_safe: Option<bool>,
+  remote: Option<RemoteTarget>,
+  sandbox: Option<SandboxOptions>,
 ) -> Result<String, String> {
   let id = Uuid::new_v4().to_string();
 
-  let pty_system = native_pty_system();
-  let pair = pty_system
-    .openpty(PtySize {
-      rows,
-      cols,
-      pixel_width: 0,
-      pixel_height: 0,
-    })
-    .map_err(|e| e.to_string())?;
-
-  let mut cmd = default_shell_command();
-  if let Some(dir) = cwd {
-    cmd.cwd(dir);
+  if remote.is_some() && sandbox.is_some() {
+    return Err("sandbox isolation is not supported for remote sessions".to_string());
   }
 
-  let child = pair.slave.spawn_command(cmd).map_err(|e| e.to_string())?;
+  let (backend, mut reader, writer): (PtyBackend, Box<dyn Read + Send>, Box<dyn Write + Send>) =
+    if let Some(remote) = remote {
+      let (session, channel) = open_remote_shell(&remote, cols, rows)?;
+      let channel = Arc::new(Mutex::new(channel));
+      let reader: Box<dyn Read + Send> = Box::new(ChannelIo(channel.clone()));
+      let writer: Box<dyn Write + Send> = Box::new(ChannelIo(channel.clone()));
+      (
+        PtyBackend::Remote {
+          channel,
+          _session: session,
+        },
+        reader,
+        writer,
+      )
+    } else {
+      let pty_system = native_pty_system();
+      let pair = pty_system
+        .openpty(PtySize {
+          rows,
+          cols,
+          pixel_width: 0,
+          pixel_height: 0,
+        })
+        .map_err(|e| e.to_string())?;
+
+      let mut cmd = build_command(shell, env);
+      if let Some(dir) = cwd {
+        cmd.cwd(dir);
+      }
+
+      // When a new PID namespace is requested, `apply_sandbox` forks again
+      // so the exec'd shell isn't the process `spawn_command` forked (see
+      // the comment on `PtyBackend::Local::real_pid`). It reports that
+      // real pid back over this pipe.
+      #[cfg(target_os = "linux")]
+      let mut pid_pipe: Option<(std::os::fd::RawFd, std::os::fd::RawFd)> = None;
+      #[cfg(target_os = "linux")]
+      if let Some(sandbox) = sandbox {
+        let wants_pid_ns = sandbox.new_pid_ns.unwrap_or(false);
+        let report_fd = if wants_pid_ns {
+          let mut fds = [0 as std::os::fd::RawFd; 2];
+          if unsafe { libc::pipe(fds.as_mut_ptr()) } != 0 {
+            return Err(std::io::Error::last_os_error().to_string());
+          }
+          let (read_fd, write_fd) = (fds[0], fds[1]);
+          pid_pipe = Some((read_fd, write_fd));
+          Some(write_fd)
+        } else {
+          None
+        };
+
+        unsafe {
+          cmd.pre_exec(move || apply_sandbox(&sandbox, report_fd));
+        }
+      }
+      #[cfg(not(target_os = "linux"))]
+      if sandbox.is_some() {
+        return Err("sandbox isolation is only supported on Linux".to_string());
+      }
 
-  let master = pair.master;
-  let mut reader = master.try_clone_reader().map_err(|e| e.to_string())?;
-  let writer = master.take_writer().map_err(|e| e.to_string())?;
+      let child = pair.slave.spawn_command(cmd).map_err(|e| e.to_string())?;
+
+      #[cfg(target_os = "linux")]
+      let real_pid = pid_pipe.and_then(|(read_fd, write_fd)| {
+        // `spawn_command`'s fork inherited our copy of the write end; close
+        // it so that if the reporting child dies before writing, `read` below
+        // sees EOF instead of blocking forever.
+        unsafe { libc::close(write_fd) };
+
+        let mut bytes = [0u8; std::mem::size_of::<i32>()];
+        let mut read_so_far = 0;
+        while read_so_far < bytes.len() {
+          let n = unsafe {
+            libc::read(
+              read_fd,
+              bytes[read_so_far..].as_mut_ptr().cast(),
+              bytes.len() - read_so_far,
+            )
+          };
+          if n <= 0 {
+            break;
+          }
+          read_so_far += n as usize;
+        }
+        unsafe { libc::close(read_fd) };
+        // A short read means the reporting process died before writing its
+        // pid; fall back to treating `child` as the real process.
+        (read_so_far == bytes.len()).then(|| i32::from_ne_bytes(bytes))
+      });
+      #[cfg(not(target_os = "linux"))]
+      let real_pid = None;
+
+      let master = pair.master;
+      let reader: Box<dyn Read + Send> =
+        master.try_clone_reader().map_err(|e| e.to_string())?;
+      let writer: Box<dyn Write + Send> = master.take_writer().map_err(|e| e.to_string())?;
+
+      (
+        PtyBackend::Local {
+          master,
+          child,
+          real_pid,
+        },
+        reader,
+        writer,
+      )
+    };
+
+  let term_size = TermSize {
+    cols: cols as usize,
+    rows: rows as usize,
+  };
+  let title = Arc::new(Mutex::new(String::new()));
+  let event_proxy = EventProxy {
+    app: app.clone(),
+    id: id.clone(),
+    title: title.clone(),
+  };
+  let term = Arc::new(Mutex::new(Term::new(
+    TermConfig::default(),
+    &term_size,
+    event_proxy,
+  )));
 
   {
     // IMPORTANT: use the guard directly (no Ok(...))
@@ -94,30 +747,60 @@ pub fn terminal_create(
     map.insert(
       id.clone(),
       TermSession {
-        master,
+        backend,
         writer,
-        child,
+        term: term.clone(),
+        title,
+        cols: Mutex::new(cols),
+        rows: Mutex::new(rows),
+        exit_status: Mutex::new(None),
       },
     );
   }
 
-  // Stream PTY output -> frontend via Tauri events
+  // Stream PTY output -> frontend via Tauri events, while also feeding the
+  // server-side grid model so terminal_get_screen/terminal_scroll stay live.
   let app_for_thread = app.clone();
   let id_for_thread = id.clone();
+  let binary_safe = binary_safe.unwrap_or(false);
   std::thread::spawn(move || {
+    let mut parser = Processor::new();
     let mut buf = [0u8; 8192];
+    // Trailing bytes from the previous read that didn't form a complete
+    // UTF-8 scalar yet; unused when `binary_safe` is set.
+    let mut carry: Vec<u8> = Vec::new();
     loop {
       match reader.read(&mut buf) {
         Ok(0) => break,
         Ok(n) => {
-          let chunk = String::from_utf8_lossy(&buf[..n]).to_string();
-          let _ = app_for_thread.emit(
-            "terminal:data",
-            TerminalDataEvent {
-              id: id_for_thread.clone(),
-              data: chunk,
-            },
-          );
+          {
+            let mut term = term.lock().expect("terminal grid poisoned");
+            for byte in &buf[..n] {
+              parser.advance(&mut *term, *byte);
+            }
+          }
+
+          if binary_safe {
+            let _ = app_for_thread.emit(
+              "terminal:data-binary",
+              TerminalBinaryDataEvent {
+                id: id_for_thread.clone(),
+                data: BASE64.encode(&buf[..n]),
+              },
+            );
+            continue;
+          }
+
+          let text = decode_chunk(&mut carry, &buf[..n]);
+          if !text.is_empty() {
+            let _ = app_for_thread.emit(
+              "terminal:data",
+              TerminalDataEvent {
+                id: id_for_thread.clone(),
+                data: text,
+              },
+            );
+          }
         }
         Err(_) => break,
       }
@@ -153,24 +836,214 @@ pub fn terminal_resize(
   let map = state.lock();
   let session = map.get(&id).ok_or("unknown terminal id")?;
 
+  match &session.backend {
+    PtyBackend::Local { master, .. } => master
+      .resize(PtySize {
+        rows,
+        cols,
+        pixel_width: 0,
+        pixel_height: 0,
+      })
+      .map_err(|e| e.to_string())?,
+    PtyBackend::Remote { channel, .. } => {
+      // `ssh2::Error` converts to `io::Error` preserving `ErrorKind::WouldBlock`
+      // for EAGAIN, which is what `retry_on_would_block` matches on.
+      retry_on_would_block(channel, |channel| {
+        channel
+          .request_pty_size(cols as u32, rows as u32, None, None)
+          .map_err(std::io::Error::from)
+      })
+      .map_err(|e| e.to_string())?
+    }
+  }
+
+  let term_size = TermSize {
+    cols: cols as usize,
+    rows: rows as usize,
+  };
   session
-    .master
-    .resize(PtySize {
-      rows,
-      cols,
-      pixel_width: 0,
-      pixel_height: 0,
-    })
-    .map_err(|e| e.to_string())?;
+    .term
+    .lock()
+    .expect("terminal grid poisoned")
+    .resize(term_size);
+
+  *session.cols.lock().expect("terminal cols poisoned") = cols;
+  *session.rows.lock().expect("terminal rows poisoned") = rows;
 
   Ok(())
 }
 
+#[tauri::command]
+pub fn terminal_list(state: State<TerminalState>) -> Result<Vec<TerminalSummary>, String> {
+  let mut map = state.lock();
+  Ok(
+    map
+      .iter_mut()
+      .map(|(id, session)| TerminalSummary {
+        id: id.clone(),
+        title: session.title.lock().expect("terminal title poisoned").clone(),
+        cols: *session.cols.lock().expect("terminal cols poisoned"),
+        rows: *session.rows.lock().expect("terminal rows poisoned"),
+        alive: backend_alive(&mut session.backend, &session.exit_status),
+      })
+      .collect(),
+  )
+}
+
+#[tauri::command]
+pub fn terminal_status(state: State<TerminalState>, id: String) -> Result<TerminalStatus, String> {
+  let mut map = state.lock();
+  let session = map.get_mut(&id).ok_or("unknown terminal id")?;
+
+  let alive = backend_alive(&mut session.backend, &session.exit_status);
+  let exit_status = *session.exit_status.lock().expect("terminal exit status poisoned");
+
+  Ok(TerminalStatus { alive, exit_status })
+}
+
 #[tauri::command]
 pub fn terminal_kill(state: State<TerminalState>, id: String) -> Result<(), String> {
   let mut map = state.lock();
-  if let Some(mut session) = map.remove(&id) {
-    let _ = session.child.kill();
+  if let Some(session) = map.remove(&id) {
+    match session.backend {
+      PtyBackend::Local {
+        mut child,
+        real_pid,
+        ..
+      } => {
+        // `child` may be a reaper (see `PtyBackend::Local::real_pid`); signal
+        // the actual sandboxed shell directly too, not just the reaper.
+        #[cfg(unix)]
+        if let Some(pid) = real_pid {
+          unsafe {
+            libc::kill(pid, libc::SIGKILL);
+          }
+        }
+        #[cfg(not(unix))]
+        let _ = real_pid;
+
+        let _ = child.kill();
+      }
+      PtyBackend::Remote { channel, .. } => {
+        let _ = retry_on_would_block(&channel, |channel| {
+          channel.close().map_err(std::io::Error::from)
+        });
+      }
+    }
+  }
+  Ok(())
+}
+
+#[tauri::command]
+pub fn terminal_get_screen(state: State<TerminalState>, id: String) -> Result<TerminalScreen, String> {
+  let map = state.lock();
+  let session = map.get(&id).ok_or("unknown terminal id")?;
+  let term = session.term.lock().expect("terminal grid poisoned");
+
+  let content = term.renderable_content();
+  let cols = term.columns();
+  let rows = term.screen_lines();
+  // `Line` goes negative for scrollback rows pulled into view by
+  // terminal_scroll; add the display offset to land back in row-space
+  // instead of clamping every visible scrollback row onto row 0.
+  let display_offset = content.display_offset as i32;
+
+  let mut cells = Vec::new();
+  for cell in content.display_iter {
+    if cell.c != ' ' {
+      cells.push(TerminalCell {
+        row: (cell.point.line.0 + display_offset) as usize,
+        col: cell.point.column.0,
+        ch: cell.c,
+      });
+    }
   }
+
+  let cursor = TerminalCursor {
+    row: (content.cursor.point.line.0 + display_offset) as usize,
+    col: content.cursor.point.column.0,
+  };
+
+  Ok(TerminalScreen {
+    cols,
+    rows,
+    cells,
+    cursor,
+  })
+}
+
+#[tauri::command]
+pub fn terminal_get_title(state: State<TerminalState>, id: String) -> Result<String, String> {
+  let map = state.lock();
+  let session = map.get(&id).ok_or("unknown terminal id")?;
+  Ok(session.title.lock().expect("terminal title poisoned").clone())
+}
+
+#[tauri::command]
+pub fn terminal_scroll(state: State<TerminalState>, id: String, delta: i32) -> Result<(), String> {
+  let map = state.lock();
+  let session = map.get(&id).ok_or("unknown terminal id")?;
+
+  session
+    .term
+    .lock()
+    .expect("terminal grid poisoned")
+    .scroll_display(Scroll::Delta(delta));
+
   Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+  use super::decode_chunk;
+
+  #[test]
+  fn decodes_whole_chunks_immediately() {
+    let mut carry = Vec::new();
+    assert_eq!(decode_chunk(&mut carry, b"hello"), "hello");
+    assert!(carry.is_empty());
+  }
+
+  #[test]
+  fn carries_a_multibyte_sequence_split_across_reads() {
+    let bytes = "é".as_bytes(); // 0xC3 0xA9
+    let mut carry = Vec::new();
+
+    assert_eq!(decode_chunk(&mut carry, &bytes[..1]), "");
+    assert_eq!(carry, &bytes[..1]);
+
+    assert_eq!(decode_chunk(&mut carry, &bytes[1..]), "é");
+    assert!(carry.is_empty());
+  }
+
+  #[test]
+  fn passes_through_an_escape_sequence_split_across_reads() {
+    let mut carry = Vec::new();
+
+    // Escape sequences are plain ASCII, so a split mid-sequence doesn't
+    // need carrying - each half is valid UTF-8 on its own.
+    assert_eq!(decode_chunk(&mut carry, b"\x1b["), "\x1b[");
+    assert_eq!(decode_chunk(&mut carry, b"31m"), "31m");
+    assert!(carry.is_empty());
+  }
+
+  #[test]
+  fn replaces_a_genuinely_invalid_byte_instead_of_stalling() {
+    let mut carry = Vec::new();
+
+    // 0x80 is a lone continuation byte: invalid on its own, not just
+    // truncated, so it must be dropped rather than carried forever.
+    let text = decode_chunk(&mut carry, b"ok\x80more");
+    assert_eq!(text, "ok\u{FFFD}more");
+    assert!(carry.is_empty());
+  }
+
+  #[test]
+  fn recovers_and_keeps_streaming_after_an_invalid_byte() {
+    let mut carry = Vec::new();
+
+    decode_chunk(&mut carry, b"\x80");
+    assert!(carry.is_empty());
+    assert_eq!(decode_chunk(&mut carry, b"still here"), "still here");
+  }
+}